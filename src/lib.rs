@@ -42,14 +42,24 @@ impl<const SIZE: usize, T> core::ops::DerefMut for ReprCWrapper<SIZE, T> {
 }
 
 impl<const SIZE: usize, T> ReprCWrapper<SIZE, T> {
+    /// Static assert that `T` fits within `SIZE` `u64`s and doesn't exceed `Self`'s alignment
+    ///
+    /// Referenced by every constructor so a mismatched `SIZE` or an over-aligned `T` fails to
+    /// compile at monomorphization time, rather than panicking the first time the code path runs.
+    const CHECK: () = {
+        assert!(align_of::<T>() <= align_of::<Self>());
+        assert!(SIZE == size_of::<ManuallyDrop::<T>>().div_ceil(size_of::<u64>()));
+    };
+
     /// Returns a `ReprCWrapper` from a `T`
     pub fn new(val: T) -> Self {
-        assert!(align_of::<T>() <= align_of::<Self>());
-        assert_eq!(SIZE, (size_of::<ManuallyDrop::<T>>() + size_of::<u64>() - 1) / size_of::<u64>());
+        let () = Self::CHECK;
 
         let val = ManuallyDrop::<T>::new(val);
+        // Zero the whole buffer first, so that any trailing bytes beyond size_of::<T>() are
+        // well-defined. Writing T is a typed copy, so T's own internal padding (if any) is not.
         let mut wrapper = Self {
-            buffer: MaybeUninit::uninit(),
+            buffer: MaybeUninit::new([0u64; SIZE]),
             phantom: core::marker::PhantomData
         };
         unsafe{ (wrapper.buffer.as_mut_ptr().cast::<ManuallyDrop::<T>>()).write(val); }
@@ -73,14 +83,294 @@ impl<const SIZE: usize, T> ReprCWrapper<SIZE, T> {
     }
 }
 
-/// A `ReprCWrapper` type that corresponds to a wrapped version of `T`
+/// An uninitialized `ReprCWrapper`, for constructing one without first materializing a `T`
+///
+/// Unlike `ReprCWrapper`, this has no `Drop` impl: dropping a `ReprCWrapperUninit` that was
+/// never `assume_init`-ed just drops the (uninitialized) buffer and does not run `T`'s
+/// destructor, the same way `core::mem::MaybeUninit<T>` behaves.
+#[repr(C)]
+pub struct ReprCWrapperUninit<const SIZE: usize, T> {
+    buffer: MaybeUninit<[u64; SIZE]>,
+    phantom: core::marker::PhantomData<T>,
+}
+
+impl<const SIZE: usize, T> ReprCWrapperUninit<SIZE, T> {
+    /// Returns a zeroed, uninitialized `ReprCWrapperUninit`
+    ///
+    /// Zeroing (rather than leaving the buffer fully uninitialized) keeps the same tail-padding
+    /// guarantee `ReprCWrapper::new` provides, in case the wrapper is later read as bytes.
+    pub fn uninit() -> Self {
+        let () = ReprCWrapper::<SIZE, T>::CHECK;
+
+        Self {
+            buffer: MaybeUninit::new([0u64; SIZE]),
+            phantom: core::marker::PhantomData
+        }
+    }
+
+    /// Returns a pointer to the start of the backing buffer, for an FFI callback to write `T` into
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.buffer.as_mut_ptr().cast::<T>()
+    }
+
+    /// Asserts that the buffer has been initialized with a valid `T`, yielding a `ReprCWrapper`
+    ///
+    /// # Safety
+    /// The caller must have written a valid `T` through `as_mut_ptr` first.
+    pub unsafe fn assume_init(self) -> ReprCWrapper<SIZE, T> {
+        // No `Drop` impl on `Self`, so letting `self` drop here just discards the
+        // (already-copied) buffer without running any destructor.
+        let buffer = unsafe{ core::ptr::read(&self.buffer) };
+        ReprCWrapper { buffer, phantom: core::marker::PhantomData }
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+impl<const SIZE: usize, T: zerocopy::IntoBytes> ReprCWrapper<SIZE, T> {
+    /// Returns the wrapper's backing storage as a `SIZE * size_of::<u64>()`-byte slice
+    ///
+    /// `T: IntoBytes` guarantees `T` has no internal padding, and `new`/`from` zero the whole
+    /// backing buffer before writing `T` into it, so every byte returned here is well-defined.
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe{ core::slice::from_raw_parts(self.buffer.as_ptr().cast::<u8>(), SIZE * size_of::<u64>()) }
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+impl<const SIZE: usize, T: zerocopy::FromBytes> ReprCWrapper<SIZE, T> {
+    /// Rebuilds a `ReprCWrapper` from bytes previously produced by `as_bytes`
+    ///
+    /// Panics if `bytes.len() != SIZE * size_of::<u64>()`.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let () = Self::CHECK;
+
+        assert_eq!(bytes.len(), SIZE * size_of::<u64>());
+        let mut buffer = [0u64; SIZE];
+        unsafe{ core::ptr::copy_nonoverlapping(bytes.as_ptr(), buffer.as_mut_ptr().cast::<u8>(), bytes.len()); }
+        Self { buffer: MaybeUninit::new(buffer), phantom: core::marker::PhantomData }
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+impl<const SIZE: usize, T: zerocopy::TryFromBytes + zerocopy::KnownLayout + zerocopy::Immutable> ReprCWrapper<SIZE, T> {
+    /// Attempts to rebuild a `ReprCWrapper` from bytes, validating `T`'s bit-pattern first
+    ///
+    /// Returns `None` if `bytes.len() != SIZE * size_of::<u64>()`, or if the leading
+    /// `size_of::<T>()` bytes aren't a valid bit-pattern for `T` (e.g. an invalid enum
+    /// discriminant or a non-boolean `bool`). The candidate buffer is validated before any `T`
+    /// is allowed to exist, so a corrupted or wrong-variant blob never produces a live `T`.
+    pub fn try_from_bytes(bytes: &[u8]) -> Option<Self> {
+        let () = Self::CHECK;
+
+        if bytes.len() != SIZE * size_of::<u64>() {
+            return None;
+        }
+        let mut buffer = [0u64; SIZE];
+        unsafe{ core::ptr::copy_nonoverlapping(bytes.as_ptr(), buffer.as_mut_ptr().cast::<u8>(), bytes.len()); }
+
+        let candidate = unsafe{ core::slice::from_raw_parts(buffer.as_ptr().cast::<u8>(), size_of::<T>()) };
+        T::try_ref_from_bytes(candidate).ok()?;
+
+        Some(Self { buffer: MaybeUninit::new(buffer), phantom: core::marker::PhantomData })
+    }
+}
+
+/// Zero-sized alignment carrier type used to select an `HasAlignMarker::Marker` for `ALIGN`
+///
+/// `pub` (rather than `pub(crate)`) only because it appears in the `where` bound of the public
+/// `ReprCWrapperAligned`; it has no fields or methods, so it isn't otherwise useful outside
+/// this crate.
+pub struct AlignTag<const ALIGN: usize>;
+
+/// Associates an alignment value with a concrete zero-sized type of that alignment
+///
+/// Implemented only for the alignments `align_marker!` below emits, so instantiating
+/// `ReprCWrapperAligned` with an unsupported `ALIGN` fails to compile rather than silently
+/// under-aligning the buffer. `pub` for the same reason as `AlignTag`; not meant to be
+/// implemented outside this crate.
+pub trait HasAlignMarker {
+    #[doc(hidden)]
+    type Marker: Copy;
+}
+
+macro_rules! align_marker {
+    ( $( $align:literal => $name:ident ),* $(,)? ) => {
+        $(
+            // `pub` because it's named by the public `HasAlignMarker::Marker` associated type;
+            // it carries no fields or methods, so it isn't otherwise usable outside this crate.
+            #[doc(hidden)]
+            #[repr(align($align))]
+            #[derive(Clone, Copy)]
+            pub struct $name;
+
+            impl HasAlignMarker for AlignTag<$align> {
+                type Marker = $name;
+            }
+        )*
+    };
+}
+align_marker!(8 => Align8, 16 => Align16, 32 => Align32, 64 => Align64, 128 => Align128, 256 => Align256);
+
+/// Like `ReprCWrapper`, but carries an explicit `ALIGN` so `T` can be over-aligned (e.g. a SIMD
+/// type, or anything `#[repr(align(N))]` for `N` > 8)
+///
+/// NOTE: `ALIGN` must be one of the alignments `HasAlignMarker` is implemented for (currently
+/// 8/16/32/64/128/256); `repr_c_wrapper_t!` picks the right one automatically from `align_of::<T>()`.
+#[repr(C)]
+pub struct ReprCWrapperAligned<const SIZE: usize, const ALIGN: usize, T>
+where AlignTag<ALIGN>: HasAlignMarker
+{
+    buffer: MaybeUninit<[u64; SIZE]>,
+    align_marker: [<AlignTag<ALIGN> as HasAlignMarker>::Marker; 0],
+    phantom: core::marker::PhantomData<T>,
+}
+
+impl<const SIZE: usize, const ALIGN: usize, T> From<T> for ReprCWrapperAligned<SIZE, ALIGN, T>
+where AlignTag<ALIGN>: HasAlignMarker
+{
+    fn from(val: T) -> Self {
+        Self::new(val)
+    }
+}
+
+impl<const SIZE: usize, const ALIGN: usize, T> Drop for ReprCWrapperAligned<SIZE, ALIGN, T>
+where AlignTag<ALIGN>: HasAlignMarker
+{
+    fn drop(&mut self) {
+        unsafe{
+            let val = &mut *self.buffer.as_mut_ptr().cast::<ManuallyDrop<T>>();
+            ManuallyDrop::<T>::drop(val);
+        }
+    }
+}
+
+impl<const SIZE: usize, const ALIGN: usize, T> core::ops::Deref for ReprCWrapperAligned<SIZE, ALIGN, T>
+where AlignTag<ALIGN>: HasAlignMarker
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe{ &*addr_of!(self.buffer).cast::<ManuallyDrop::<T>>() }
+    }
+}
+
+impl<const SIZE: usize, const ALIGN: usize, T> core::ops::DerefMut for ReprCWrapperAligned<SIZE, ALIGN, T>
+where AlignTag<ALIGN>: HasAlignMarker
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe{ &mut *addr_of_mut!(self.buffer).cast::<ManuallyDrop::<T>>() }
+    }
+}
+
+impl<const SIZE: usize, const ALIGN: usize, T> ReprCWrapperAligned<SIZE, ALIGN, T>
+where AlignTag<ALIGN>: HasAlignMarker
+{
+    /// Static assert that `T` fits within `SIZE` `u64`s and doesn't exceed `ALIGN`
+    const CHECK: () = {
+        assert!(align_of::<T>() <= ALIGN);
+        assert!(SIZE == size_of::<ManuallyDrop::<T>>().div_ceil(size_of::<u64>()));
+    };
+
+    /// Returns a `ReprCWrapperAligned` from a `T`
+    pub fn new(val: T) -> Self {
+        let () = Self::CHECK;
+
+        let val = ManuallyDrop::<T>::new(val);
+        let mut wrapper = Self {
+            buffer: MaybeUninit::new([0u64; SIZE]),
+            align_marker: [],
+            phantom: core::marker::PhantomData
+        };
+        unsafe{ (wrapper.buffer.as_mut_ptr().cast::<ManuallyDrop::<T>>()).write(val); }
+        wrapper
+    }
+
+    /// Consumes the `ReprCWrapperAligned`, and returns the inner `T`
+    pub fn into_inner(self) -> T {
+        let val = unsafe{ core::ptr::read(addr_of!(self.buffer).cast::<ManuallyDrop<T>>()) };
+        core::mem::forget(self);
+        ManuallyDrop::<T>::into_inner(val)
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+impl<const SIZE: usize, const ALIGN: usize, T: zerocopy::IntoBytes> ReprCWrapperAligned<SIZE, ALIGN, T>
+where AlignTag<ALIGN>: HasAlignMarker
+{
+    /// Returns the wrapper's backing storage as a `SIZE * size_of::<u64>()`-byte slice
+    ///
+    /// `T: IntoBytes` guarantees `T` has no internal padding, and `new`/`from` zero the whole
+    /// backing buffer before writing `T` into it, so every byte returned here is well-defined.
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe{ core::slice::from_raw_parts(self.buffer.as_ptr().cast::<u8>(), SIZE * size_of::<u64>()) }
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+impl<const SIZE: usize, const ALIGN: usize, T: zerocopy::FromBytes> ReprCWrapperAligned<SIZE, ALIGN, T>
+where AlignTag<ALIGN>: HasAlignMarker
+{
+    /// Rebuilds a `ReprCWrapperAligned` from bytes previously produced by `as_bytes`
+    ///
+    /// Panics if `bytes.len() != SIZE * size_of::<u64>()`.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let () = Self::CHECK;
+
+        assert_eq!(bytes.len(), SIZE * size_of::<u64>());
+        let mut buffer = [0u64; SIZE];
+        unsafe{ core::ptr::copy_nonoverlapping(bytes.as_ptr(), buffer.as_mut_ptr().cast::<u8>(), bytes.len()); }
+        Self { buffer: MaybeUninit::new(buffer), align_marker: [], phantom: core::marker::PhantomData }
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+impl<const SIZE: usize, const ALIGN: usize, T: zerocopy::TryFromBytes + zerocopy::KnownLayout + zerocopy::Immutable> ReprCWrapperAligned<SIZE, ALIGN, T>
+where AlignTag<ALIGN>: HasAlignMarker
+{
+    /// Attempts to rebuild a `ReprCWrapperAligned` from bytes, validating `T`'s bit-pattern first
+    ///
+    /// Returns `None` if `bytes.len() != SIZE * size_of::<u64>()`, or if the leading
+    /// `size_of::<T>()` bytes aren't a valid bit-pattern for `T` (e.g. an invalid enum
+    /// discriminant or a non-boolean `bool`). The candidate buffer is validated before any `T`
+    /// is allowed to exist, so a corrupted or wrong-variant blob never produces a live `T`.
+    pub fn try_from_bytes(bytes: &[u8]) -> Option<Self> {
+        let () = Self::CHECK;
+
+        if bytes.len() != SIZE * size_of::<u64>() {
+            return None;
+        }
+        let mut buffer = [0u64; SIZE];
+        unsafe{ core::ptr::copy_nonoverlapping(bytes.as_ptr(), buffer.as_mut_ptr().cast::<u8>(), bytes.len()); }
+
+        // Validate against `wrapper.buffer`, not the stack-local `buffer` above: unlike `buffer`,
+        // which is only 8-aligned, `wrapper.buffer` inherits `Self`'s `ALIGN`-aligned layout, so
+        // `try_ref_from_bytes` (which needs a fully-aligned reference) can check it.
+        let wrapper = Self { buffer: MaybeUninit::new(buffer), align_marker: [], phantom: core::marker::PhantomData };
+        let candidate = unsafe{ core::slice::from_raw_parts(wrapper.buffer.as_ptr().cast::<u8>(), size_of::<T>()) };
+        if T::try_ref_from_bytes(candidate).is_err() {
+            // `wrapper` doesn't hold a valid `T`, so it must not reach `Drop`
+            core::mem::forget(wrapper);
+            return None;
+        }
+
+        Some(wrapper)
+    }
+}
+
+/// A `ReprCWrapperAligned` type that corresponds to a wrapped version of `T`
 ///
-/// NOTE: This macro is a stop-gap convenience to automatically get the correct type size,
-/// until a future version of Rust stabilizes `generic_const_exprs`.  At that point, it will
-/// be as simple as using `ReprCWrapper<T>`.
+/// NOTE: This macro is a stop-gap convenience to automatically get the correct type size and
+/// alignment, until a future version of Rust stabilizes `generic_const_exprs`.  At that point,
+/// it will be as simple as using `ReprCWrapper<T>`.
 #[macro_export]
 macro_rules! repr_c_wrapper_t {
-    ( $t:ty ) => { $crate::ReprCWrapper<{(core::mem::size_of::<core::mem::ManuallyDrop::<$t>>() + core::mem::size_of::<u64>() - 1) / core::mem::size_of::<u64>()}, $t> };
+    ( $t:ty ) => {
+        $crate::ReprCWrapperAligned<
+            {(core::mem::size_of::<core::mem::ManuallyDrop::<$t>>() + core::mem::size_of::<u64>() - 1) / core::mem::size_of::<u64>()},
+            {let align = core::mem::align_of::<$t>(); if align > 8 { align } else { 8 }},
+            $t
+        >
+    };
 }
 
 #[cfg(test)]
@@ -129,8 +419,7 @@ mod test {
     struct WellAlignedCWrapper(repr_c_wrapper_t!(WellAligned));
 
     #[test]
-    #[should_panic]
-    pub fn test_against_unaligned() {
+    pub fn test_over_aligned_type() {
         let wrapped_wa = WellAlignedCWrapper(WellAligned([0; 256]).into());
         assert_eq!(&*wrapped_wa.0, &WellAligned([0; 256]));
         let wrapped_wa = WellAlignedCWrapper(WellAligned([0; 256]).into());